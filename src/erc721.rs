@@ -0,0 +1,154 @@
+//! Minimal ERC-721 storage and external interface, following the structure
+//! of the Stylus ERC-721 example: balances/owners/approvals live in their
+//! own `sol_storage!` struct so `PureRandom` can embed it via `#[borrow]`
+//! and inherit its public methods.
+
+use stylus_sdk::{
+    alloy_primitives::{Address, U256},
+    alloy_sol_types::sol,
+    evm, msg,
+    prelude::*,
+};
+
+sol! {
+    event Transfer(address indexed from, address indexed to, uint256 indexed token_id);
+    event Approval(address indexed owner, address indexed approved, uint256 indexed token_id);
+    event ApprovalForAll(address indexed owner, address indexed operator, bool approved);
+
+    error NotMinted(uint256 token_id);
+    error NotOwner(address from, uint256 token_id, address real_owner);
+    error NotApproved(address operator, uint256 token_id);
+}
+
+#[derive(SolidityError)]
+pub enum Erc721Error {
+    NotMinted(NotMinted),
+    NotOwner(NotOwner),
+    NotApproved(NotApproved),
+}
+
+sol_storage! {
+    pub struct Erc721 {
+        mapping(uint256 => address) owners;
+        mapping(address => uint256) balances;
+        mapping(uint256 => address) token_approvals;
+        mapping(address => mapping(address => bool)) operator_approvals;
+        uint256 total_supply;
+    }
+}
+
+impl Erc721 {
+    /// Mints the next sequential token id to `to`. Used internally by
+    /// `PureRandom::mint_card`; not exposed directly since card numbers must
+    /// be generated alongside the token.
+    pub fn mint(&mut self, to: Address) -> U256 {
+        let token_id = self.total_supply.get() + U256::from(1);
+        self.total_supply.set(token_id);
+        self.owners.setter(token_id).set(to);
+        let balance = self.balances.get(to);
+        self.balances.setter(to).set(balance + U256::from(1));
+        evm::log(Transfer {
+            from: Address::ZERO,
+            to,
+            token_id,
+        });
+        token_id
+    }
+
+    fn require_owner(&self, token_id: U256) -> Result<Address, Erc721Error> {
+        let owner = self.owners.get(token_id);
+        if owner.is_zero() {
+            return Err(Erc721Error::NotMinted(NotMinted { token_id }));
+        }
+        Ok(owner)
+    }
+
+    /// Whether `spender` may act on `token_id`: its owner, its approved
+    /// address, or an operator approved for the whole owner's balance.
+    pub fn is_authorized(&self, spender: Address, token_id: U256) -> Result<bool, Erc721Error> {
+        let owner = self.require_owner(token_id)?;
+        let approved = self.token_approvals.get(token_id);
+        Ok(spender == owner || spender == approved || self.operator_approvals.get(owner).get(spender))
+    }
+}
+
+#[public]
+impl Erc721 {
+    pub fn owner_of(&self, token_id: U256) -> Result<Address, Erc721Error> {
+        self.require_owner(token_id)
+    }
+
+    pub fn balance_of(&self, owner: Address) -> U256 {
+        self.balances.get(owner)
+    }
+
+    pub fn total_supply(&self) -> U256 {
+        self.total_supply.get()
+    }
+
+    pub fn get_approved(&self, token_id: U256) -> Result<Address, Erc721Error> {
+        self.require_owner(token_id)?;
+        Ok(self.token_approvals.get(token_id))
+    }
+
+    pub fn is_approved_for_all(&self, owner: Address, operator: Address) -> bool {
+        self.operator_approvals.get(owner).get(operator)
+    }
+
+    pub fn approve(&mut self, to: Address, token_id: U256) -> Result<(), Erc721Error> {
+        let owner = self.require_owner(token_id)?;
+        let sender = msg::sender();
+        if sender != owner && !self.operator_approvals.get(owner).get(sender) {
+            return Err(Erc721Error::NotOwner(NotOwner {
+                from: sender,
+                token_id,
+                real_owner: owner,
+            }));
+        }
+        self.token_approvals.setter(token_id).set(to);
+        evm::log(Approval {
+            owner,
+            approved: to,
+            token_id,
+        });
+        Ok(())
+    }
+
+    pub fn set_approval_for_all(&mut self, operator: Address, approved: bool) {
+        let owner = msg::sender();
+        self.operator_approvals.setter(owner).setter(operator).set(approved);
+        evm::log(ApprovalForAll {
+            owner,
+            operator,
+            approved,
+        });
+    }
+
+    pub fn transfer_from(&mut self, from: Address, to: Address, token_id: U256) -> Result<(), Erc721Error> {
+        let owner = self.require_owner(token_id)?;
+        if owner != from {
+            return Err(Erc721Error::NotOwner(NotOwner {
+                from,
+                token_id,
+                real_owner: owner,
+            }));
+        }
+        let sender = msg::sender();
+        if !self.is_authorized(sender, token_id)? {
+            return Err(Erc721Error::NotApproved(NotApproved {
+                operator: sender,
+                token_id,
+            }));
+        }
+
+        self.token_approvals.setter(token_id).set(Address::ZERO);
+        self.owners.setter(token_id).set(to);
+        let from_balance = self.balances.get(from);
+        self.balances.setter(from).set(from_balance - U256::from(1));
+        let to_balance = self.balances.get(to);
+        self.balances.setter(to).set(to_balance + U256::from(1));
+
+        evm::log(Transfer { from, to, token_id });
+        Ok(())
+    }
+}
@@ -2,10 +2,44 @@
 #![cfg_attr(not(feature = "export-abi"), no_main)]
 extern crate alloc;
 
-// use alloy_sol_types::sol;
+mod erc721;
+
+use alloc::vec::Vec;
+
+use alloy_sol_types::sol;
+use erc721::{Erc721, Erc721Error};
 /// Import items from the SDK. The prelude contains common traits and macros.
 use stylus_sdk::block;
-use stylus_sdk::{alloy_primitives::U256, crypto, prelude::*};
+use stylus_sdk::msg;
+use stylus_sdk::{
+    alloy_primitives::{FixedBytes, U256},
+    crypto,
+    evm,
+    prelude::*,
+};
+
+/// Cards are standard 5x5 BINGO grids: column `c` draws from
+/// `15*c+1..=15*c+15` (B-I-N-G-O), and the center cell is a free space.
+const CARD_SIZE: u32 = 25;
+const CARD_COLUMNS: u32 = 5;
+const CARD_ROWS: u32 = 5;
+const FREE_CELL: u32 = 12; // row 2, column 2
+
+/// Number of blocks the commit phase of a round stays open. No reveal (and
+/// no draw) is accepted until this phase has fully elapsed, so a lone
+/// committer cannot commit and immediately reveal/draw with a result only
+/// they could have predicted.
+const COMMIT_WINDOW_BLOCKS: u64 = 256;
+
+/// Number of blocks the reveal phase stays open once the commit phase
+/// closes. `draw` is rejected until this has elapsed too, so nobody can
+/// reveal right before drawing and bias the result with foreknowledge of
+/// `reveal_acc`.
+const REVEAL_WINDOW_BLOCKS: u64 = 256;
+
+/// Minimum number of distinct participants who must reveal before `draw` is
+/// allowed, so `reveal_acc` can never be fully known to a single actor.
+const MIN_REVEALERS: u64 = 2;
 
 // Define some persistent storage using the Solidity ABI.
 // `Counter` will be the entrypoint.
@@ -13,14 +47,86 @@ sol_storage! {
     #[entrypoint]
     pub struct PureRandom {
         uint256 nonce;            // Incremental counter to ensure uniqueness
+
+        // Commit-reveal round state. A player first submits `keccak(secret ++ sender)`
+        // via `commit`, then later discloses `secret` via `reveal` before the
+        // deadline. Every accepted reveal is folded into `reveal_acc`, which
+        // `draw` combines with on-chain entropy to produce the final result.
+        // Commits/reveals are keyed by `round`, and `draw` advances `round`
+        // and clears `reveal_acc`/`reveal_count` so a stale, already-known
+        // accumulator can't be replayed into a second draw.
+        //
+        // The round proceeds through two enforced phases: `commit_deadlines`
+        // is set by the first `commit` of the round and closes the commit
+        // phase; `reveal_deadlines` (commit_deadline + REVEAL_WINDOW_BLOCKS)
+        // closes the reveal phase. `reveal` only runs once the commit phase
+        // has closed; `draw` only runs once the reveal phase has closed and
+        // at least `MIN_REVEALERS` distinct addresses revealed.
+        mapping(uint256 => mapping(address => bytes32)) commits;
+        mapping(uint256 => mapping(address => bool)) revealed;
+        mapping(uint256 => uint256) commit_deadlines;
+        mapping(uint256 => uint256) reveal_deadlines;
+        uint256 round;
+        uint256 reveal_acc;
+        uint256 reveal_count;
+
+        // Draw-without-replacement pool of 1..=max, implemented as an
+        // on-chain Fisher-Yates shuffle. `pool_generation` lets `reset_pool`
+        // start a fresh 1..=max range without paying to zero out the
+        // previous round's swapped slots: a slot that was never written in
+        // the current generation is treated as holding `index + 1`.
+        mapping(uint256 => mapping(uint256 => uint256)) pool;
+        uint256 pool_generation;
+        uint256 remaining;
+
+        // Numbers actually handed out by `draw_next`, keyed by
+        // `pool_generation` so `mark` can check a number was really drawn in
+        // the current game rather than trusting the caller.
+        mapping(uint256 => mapping(uint256 => bool)) drawn_numbers;
+
+        // BingoCard: each minted ERC-721 token id owns a 5x5 grid of numbers,
+        // generated once at mint time, plus a bitmask of which cells have
+        // been marked against numbers drawn so far.
+        #[borrow]
+        Erc721 erc721;
+        mapping(uint256 => mapping(uint256 => uint256)) card_cells;
+        mapping(uint256 => uint256) marked_mask;
+    }
+}
+
+sol! {
+    event RandomEvent(uint256 nonce, uint256 number, uint256 timestamp, uint256 base_fee);
+    event Marked(uint256 indexed token_id, uint256 number, uint256 cell);
+    event PoolDraw(uint256 indexed generation, uint256 picked, uint256 remaining);
+}
+
+// Stable external surface for Solidity contracts and off-chain clients,
+// mirroring how the Stylus vending-machine example declares `IVendingMachine`.
+// `cargo stylus export-abi` already derives the full ABI from the `#[public]`
+// impls below; these interfaces just pin human-readable, stable names for
+// generated bindings to target.
+sol! {
+    interface IRandom {
+        function generate() external returns (uint256);
+        function randomRange(uint256 min, uint256 max) external returns (uint256);
+        function nonce() external view returns (uint256);
+        function commit(bytes32 hash) external;
+        function reveal(uint256 secret) external;
+        function draw() external returns (uint256);
+    }
+
+    interface IBingo {
+        function resetPool(uint256 max) external;
+        function drawNext() external returns (uint256);
+        function mintCard() external returns (uint256);
+        function cardNumbers(uint256 tokenId) external view returns (uint256[] memory);
+        function mark(uint256 tokenId, uint256 number) external returns (bool);
     }
 }
 
-// sol! {
-//     event RandomEvent(uint256 timestamp, uint256 number, uint256 base_fee);
-// }
 /// Declare that `Counter` is a contract with the following external methods.
 #[public]
+#[inherit(Erc721)]
 impl PureRandom {
     /// Increments `number` and updates its value in storage.
     pub fn increment(&mut self) {
@@ -33,14 +139,606 @@ impl PureRandom {
         self.nonce.get()
     }
 
+    /// Derives a pseudo-random `U256` from several block-level entropy
+    /// sources plus the caller and the current `nonce`, then advances the
+    /// nonce so that repeated calls within the same block (or even the same
+    /// transaction) never collide.
     pub fn generate(&mut self) -> U256 {
         let timestamp = U256::from(block::timestamp());
-        U256::from_be_bytes(*crypto::keccak(timestamp.to_le_bytes::<32>()))
+        let block_number = U256::from(block::number());
+        let base_fee = U256::from(block::basefee());
+        let sender = msg::sender();
+        let nonce = self.nonce.get();
+
+        let mut preimage = Vec::with_capacity(32 * 4 + 20);
+        preimage.extend_from_slice(&timestamp.to_be_bytes::<32>());
+        preimage.extend_from_slice(&block_number.to_be_bytes::<32>());
+        preimage.extend_from_slice(&base_fee.to_be_bytes::<32>());
+        preimage.extend_from_slice(sender.as_slice());
+        preimage.extend_from_slice(&nonce.to_be_bytes::<32>());
+        let number = U256::from_be_bytes(*crypto::keccak(preimage));
+
+        let nonce = nonce + U256::from(1);
+        self.nonce.set(nonce);
+        evm::log(RandomEvent {
+            nonce,
+            number,
+            timestamp,
+            base_fee,
+        });
+
+        number
+    }
+
+    /// Draws a value uniformly distributed in `[min, max]`.
+    ///
+    /// Uses rejection sampling against `U256::MAX`'s trailing partial bucket
+    /// so the result is not biased towards the low end of the range.
+    pub fn random_range(&mut self, min: U256, max: U256) -> U256 {
+        assert!(max > min, "max must be greater than min");
+        let span = max - min + U256::from(1);
+        let limit = U256::MAX - (U256::MAX % span);
+        loop {
+            let rand = self.generate();
+            if rand < limit {
+                return min + (rand % span);
+            }
+        }
+    }
+
+    /// Commits to a secret for the current round without revealing it.
+    ///
+    /// `hash` must equal `keccak(secret ++ msg::sender())`; the secret itself
+    /// is only disclosed later via `reveal`, which keeps it hidden from other
+    /// players (and the sequencer) until every participant has committed.
+    /// The first commit of a round opens its commit phase, which closes
+    /// after `COMMIT_WINDOW_BLOCKS`; later commits in the same round must
+    /// land before that deadline.
+    pub fn commit(&mut self, hash: FixedBytes<32>) {
+        let sender = msg::sender();
+        let round = self.round.get();
+
+        let mut deadline = self.commit_deadlines.get(round);
+        if deadline == U256::ZERO {
+            deadline = U256::from(block::number() + COMMIT_WINDOW_BLOCKS);
+            self.commit_deadlines.setter(round).set(deadline);
+            self.reveal_deadlines.setter(round).set(deadline + U256::from(REVEAL_WINDOW_BLOCKS));
+        }
+        assert!(U256::from(block::number()) < deadline, "commit phase closed");
+
+        self.commits.setter(round).setter(sender).set(hash);
+        self.revealed.setter(round).setter(sender).set(false);
+    }
+
+    /// Reveals the secret behind a previous `commit`, folding it into the
+    /// round's entropy accumulator.
+    ///
+    /// Reverts if the commit phase hasn't closed yet, there is no
+    /// outstanding commit for the sender in the current round, the reveal
+    /// window has closed, the sender already revealed, or `secret` does not
+    /// match the committed hash.
+    pub fn reveal(&mut self, secret: U256) {
+        let sender = msg::sender();
+        let round = self.round.get();
+
+        let commit_deadline = self.commit_deadlines.get(round);
+        assert!(commit_deadline != U256::ZERO, "round has no commits");
+        assert!(U256::from(block::number()) >= commit_deadline, "commit phase still open");
+        let reveal_deadline = self.reveal_deadlines.get(round);
+        assert!(U256::from(block::number()) <= reveal_deadline, "reveal window closed");
+        assert!(!self.revealed.get(round).get(sender), "already revealed");
+
+        let stored_commit = self.commits.get(round).get(sender);
+        assert!(stored_commit != FixedBytes::<32>::ZERO, "no commit for sender");
+
+        let mut preimage = Vec::with_capacity(52);
+        preimage.extend_from_slice(&secret.to_be_bytes::<32>());
+        preimage.extend_from_slice(sender.as_slice());
+        let hash = FixedBytes::<32>::from(*crypto::keccak(preimage));
+        assert!(hash == stored_commit, "secret does not match commit");
+
+        self.revealed.setter(round).setter(sender).set(true);
+
+        let mut acc_preimage = Vec::with_capacity(64);
+        acc_preimage.extend_from_slice(&self.reveal_acc.get().to_be_bytes::<32>());
+        acc_preimage.extend_from_slice(&secret.to_be_bytes::<32>());
+        let acc = U256::from_be_bytes(*crypto::keccak(acc_preimage));
+        self.reveal_acc.set(acc);
+        self.reveal_count.set(self.reveal_count.get() + U256::from(1));
+    }
+
+    /// Produces the round's draw by combining every revealed secret with
+    /// `block::number()` and the contract `nonce`, then advances `round` and
+    /// clears `reveal_acc`/`reveal_count` so the now-public accumulator
+    /// cannot be replayed into a later draw.
+    ///
+    /// Reverts unless the reveal phase has fully closed and at least
+    /// `MIN_REVEALERS` distinct addresses revealed, so the result can never
+    /// be known in advance to a single actor.
+    pub fn draw(&mut self) -> U256 {
+        let round = self.round.get();
+        let reveal_deadline = self.reveal_deadlines.get(round);
+        assert!(reveal_deadline != U256::ZERO, "round has no commits");
+        assert!(U256::from(block::number()) > reveal_deadline, "reveal phase still open");
+        assert!(self.reveal_count.get() >= U256::from(MIN_REVEALERS), "not enough revealers");
+
+        let mut preimage = Vec::with_capacity(96);
+        preimage.extend_from_slice(&self.reveal_acc.get().to_be_bytes::<32>());
+        preimage.extend_from_slice(&U256::from(block::number()).to_be_bytes::<32>());
+        preimage.extend_from_slice(&self.nonce.get().to_be_bytes::<32>());
+        let result = U256::from_be_bytes(*crypto::keccak(preimage));
+
+        self.round.set(round + U256::from(1));
+        self.reveal_acc.set(U256::ZERO);
+        self.reveal_count.set(U256::ZERO);
+
+        let nonce = self.nonce.get() + U256::from(1);
+        self.nonce.set(nonce);
+        let timestamp = U256::from(block::timestamp());
+        evm::log(RandomEvent {
+            nonce,
+            number: result,
+            timestamp,
+            base_fee: U256::from(block::basefee()),
+        });
+
+        result
+    }
+
+    /// (Re)populates the number pool with the range `1..=max`, ready for
+    /// `draw_next` to hand out without replacement.
+    ///
+    /// The range is populated lazily: bumping `pool_generation` is enough to
+    /// make every slot read back as unset (i.e. `index + 1`) without writing
+    /// `max` storage slots up front.
+    pub fn reset_pool(&mut self, max: U256) {
+        assert!(max > U256::ZERO, "max must be positive");
+        let generation = self.pool_generation.get();
+        self.pool_generation.set(generation + U256::from(1));
+        self.remaining.set(max);
+    }
+
+    /// Draws the next number from the pool without replacement, via one step
+    /// of an on-chain Fisher-Yates shuffle.
+    ///
+    /// Reverts if the pool is exhausted (or was never populated).
+    pub fn draw_next(&mut self) -> U256 {
+        let remaining = self.remaining.get();
+        assert!(remaining > U256::ZERO, "pool exhausted");
+
+        let rand = self.generate();
+        let index = rand % remaining;
+        let last = remaining - U256::from(1);
+
+        let generation = self.pool_generation.get();
+        let mut pool = self.pool.setter(generation);
+
+        let picked = pool.get(index);
+        let picked = if picked.is_zero() { index + U256::from(1) } else { picked };
+
+        if index != last {
+            let last_value = pool.get(last);
+            let last_value = if last_value.is_zero() { last + U256::from(1) } else { last_value };
+            pool.setter(index).set(last_value);
+        }
+
+        self.remaining.set(last);
+        self.drawn_numbers.setter(generation).setter(picked).set(true);
+        evm::log(PoolDraw {
+            generation,
+            picked,
+            remaining: last,
+        });
+
+        picked
+    }
+
+    /// Mints a new bingo card to the caller and generates its 5x5 grid of
+    /// numbers from the contract's entropy source.
+    pub fn mint_card(&mut self) -> U256 {
+        let to = msg::sender();
+        let token_id = self.erc721.mint(to);
+        self.generate_card(token_id);
+        token_id
+    }
+
+    /// Returns the 25 numbers (row-major, free space as `0`) on a card.
+    ///
+    /// Reverts if `token_id` was never minted.
+    pub fn card_numbers(&self, token_id: U256) -> Result<Vec<U256>, Erc721Error> {
+        self.erc721.owner_of(token_id)?;
+        let cells = self.card_cells.get(token_id);
+        Ok((0..CARD_SIZE).map(|cell| cells.get(U256::from(cell))).collect())
+    }
+
+    /// Marks `number` on `token_id`'s card if it appears there, returning
+    /// whether a cell was marked, and emits `Marked` so indexers don't have
+    /// to poll storage.
+    ///
+    /// Reverts if `token_id` was never minted, the caller is not the card's
+    /// owner/approved/operator, or `number` was never actually drawn by
+    /// `draw_next` in the pool's current generation.
+    pub fn mark(&mut self, token_id: U256, number: U256) -> Result<bool, Erc721Error> {
+        let sender = msg::sender();
+        assert!(self.erc721.is_authorized(sender, token_id)?, "not authorized for token");
+        assert!(
+            self.drawn_numbers.get(self.pool_generation.get()).get(number),
+            "number has not been drawn"
+        );
+
+        let cells = self.card_cells.get(token_id);
+        for cell in 0..CARD_SIZE {
+            if cells.get(U256::from(cell)) == number {
+                let mask = self.marked_mask.get(token_id);
+                self.marked_mask.setter(token_id).set(mask | (U256::from(1) << cell));
+                evm::log(Marked {
+                    token_id,
+                    number,
+                    cell: U256::from(cell),
+                });
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+}
+
+impl PureRandom {
+    /// Fills in `token_id`'s grid: for each BINGO column, draws distinct
+    /// numbers from that column's 15-number range (5 per column, 4 in the
+    /// center column), leaving the center cell as a free space (`0`).
+    fn generate_card(&mut self, token_id: U256) {
+        for column in 0..CARD_COLUMNS {
+            let lo = U256::from(column * 15 + 1);
+            let hi = U256::from(column * 15 + 15);
+            let mut chosen = Vec::with_capacity(CARD_ROWS as usize);
+            for row in 0..CARD_ROWS {
+                let cell = row * CARD_COLUMNS + column;
+                if cell == FREE_CELL {
+                    self.card_cells.setter(token_id).setter(U256::from(cell)).set(U256::ZERO);
+                    continue;
+                }
+                loop {
+                    let candidate = self.card_random_range(token_id, lo, hi);
+                    if !chosen.contains(&candidate) {
+                        chosen.push(candidate);
+                        self.card_cells.setter(token_id).setter(U256::from(cell)).set(candidate);
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Like `random_range`, but mixes `token_id` into the preimage so a
+    /// card's numbers are a function of its own id plus the entropy source,
+    /// rather than just whatever global draw happened to be next.
+    fn card_random_range(&mut self, token_id: U256, min: U256, max: U256) -> U256 {
+        let span = max - min + U256::from(1);
+        let limit = U256::MAX - (U256::MAX % span);
+        loop {
+            let rand = self.card_entropy(token_id);
+            if rand < limit {
+                return min + (rand % span);
+            }
+        }
+    }
+
+    /// One-shot entropy draw for card generation: hashes `token_id` alongside
+    /// the same block-level sources and nonce that `generate` uses, then
+    /// advances the nonce so the draw isn't reused elsewhere.
+    fn card_entropy(&mut self, token_id: U256) -> U256 {
+        let timestamp = U256::from(block::timestamp());
+        let block_number = U256::from(block::number());
+        let base_fee = U256::from(block::basefee());
+        let sender = msg::sender();
+        let nonce = self.nonce.get();
+
+        let mut preimage = Vec::with_capacity(32 * 5 + 20);
+        preimage.extend_from_slice(&token_id.to_be_bytes::<32>());
+        preimage.extend_from_slice(&timestamp.to_be_bytes::<32>());
+        preimage.extend_from_slice(&block_number.to_be_bytes::<32>());
+        preimage.extend_from_slice(&base_fee.to_be_bytes::<32>());
+        preimage.extend_from_slice(sender.as_slice());
+        preimage.extend_from_slice(&nonce.to_be_bytes::<32>());
+        let number = U256::from_be_bytes(*crypto::keccak(preimage));
+
+        self.nonce.set(nonce + U256::from(1));
+        number
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use stylus_sdk::{alloy_primitives::Address, testing::TestVM};
+
+    fn commit_hash(secret: U256, sender: Address) -> FixedBytes<32> {
+        let mut preimage = secret.to_be_bytes::<32>().to_vec();
+        preimage.extend_from_slice(sender.as_slice());
+        FixedBytes::<32>::from(*crypto::keccak(preimage))
+    }
+
+    /// Commits and reveals `secret` for every address in `revealers`,
+    /// advancing the VM's block number across the commit/reveal deadlines so
+    /// the two-phase gating in `commit`/`reveal` is satisfied.
+    fn run_commit_reveal_round(vm: &TestVM, contract: &mut PureRandom, revealers: &[(Address, U256)]) {
+        let start_block = block::number();
+        for (sender, secret) in revealers {
+            vm.set_sender(*sender);
+            contract.commit(commit_hash(*secret, *sender));
+        }
+
+        vm.set_block_number(start_block + COMMIT_WINDOW_BLOCKS);
+        for (sender, secret) in revealers {
+            vm.set_sender(*sender);
+            contract.reveal(*secret);
+        }
+
+        vm.set_block_number(start_block + COMMIT_WINDOW_BLOCKS + REVEAL_WINDOW_BLOCKS + 1);
+    }
+
+    fn two_revealers() -> [(Address, U256); 2] {
+        [(Address::from([1u8; 20]), U256::from(7)), (Address::from([2u8; 20]), U256::from(9))]
+    }
+
+    #[test]
+    fn commit_reveal_round_produces_a_draw() {
+        let vm = TestVM::default();
+        let mut contract = PureRandom::from(&vm);
+
+        run_commit_reveal_round(&vm, &mut contract, &two_revealers());
+        contract.draw();
+    }
+
+    #[test]
+    #[should_panic(expected = "round has no commits")]
+    fn draw_reverts_without_any_reveal() {
+        let vm = TestVM::default();
+        let mut contract = PureRandom::from(&vm);
+        contract.draw();
+    }
+
+    #[test]
+    #[should_panic(expected = "round has no commits")]
+    fn draw_reverts_on_second_call_in_same_round() {
+        let vm = TestVM::default();
+        let mut contract = PureRandom::from(&vm);
+
+        run_commit_reveal_round(&vm, &mut contract, &two_revealers());
+        contract.draw();
+        // The round was reset by `draw`, so this must revert without a
+        // fresh commit/reveal rather than replaying the stale accumulator.
+        contract.draw();
+    }
+
+    #[test]
+    #[should_panic(expected = "not enough revealers")]
+    fn draw_reverts_with_too_few_revealers() {
+        let vm = TestVM::default();
+        let mut contract = PureRandom::from(&vm);
+
+        let sender = Address::from([1u8; 20]);
+        run_commit_reveal_round(&vm, &mut contract, &[(sender, U256::from(7))]);
+        contract.draw();
+    }
+
+    #[test]
+    #[should_panic(expected = "commit phase still open")]
+    fn reveal_rejects_before_commit_phase_closes() {
+        let vm = TestVM::default();
+        let mut contract = PureRandom::from(&vm);
+        let sender = Address::from([1u8; 20]);
+
+        vm.set_sender(sender);
+        let secret = U256::from(3);
+        contract.commit(commit_hash(secret, sender));
+        contract.reveal(secret);
+    }
+
+    #[test]
+    #[should_panic(expected = "reveal phase still open")]
+    fn draw_rejects_before_reveal_phase_closes() {
+        let vm = TestVM::default();
+        let mut contract = PureRandom::from(&vm);
+        let revealers = two_revealers();
+        let start_block = block::number();
+
+        for (sender, secret) in &revealers {
+            vm.set_sender(*sender);
+            contract.commit(commit_hash(*secret, *sender));
+        }
+        vm.set_block_number(start_block + COMMIT_WINDOW_BLOCKS);
+        for (sender, secret) in &revealers {
+            vm.set_sender(*sender);
+            contract.reveal(*secret);
+        }
+
+        contract.draw();
+    }
+
+    #[test]
+    #[should_panic(expected = "already revealed")]
+    fn reveal_rejects_double_reveal() {
+        let vm = TestVM::default();
+        let mut contract = PureRandom::from(&vm);
+        let sender = Address::from([1u8; 20]);
+        vm.set_sender(sender);
+
+        let secret = U256::from(3);
+        contract.commit(commit_hash(secret, sender));
+        vm.set_block_number(block::number() + COMMIT_WINDOW_BLOCKS);
+        contract.reveal(secret);
+        contract.reveal(secret);
+    }
+
+    #[test]
+    #[should_panic(expected = "secret does not match commit")]
+    fn reveal_rejects_wrong_secret() {
+        let vm = TestVM::default();
+        let mut contract = PureRandom::from(&vm);
+        let sender = Address::from([1u8; 20]);
+        vm.set_sender(sender);
+
+        contract.commit(commit_hash(U256::from(3), sender));
+        vm.set_block_number(block::number() + COMMIT_WINDOW_BLOCKS);
+        contract.reveal(U256::from(4));
+    }
+
+    #[test]
+    #[should_panic(expected = "round has no commits")]
+    fn reveal_rejects_missing_commit() {
+        let vm = TestVM::default();
+        let mut contract = PureRandom::from(&vm);
+        contract.reveal(U256::from(1));
     }
 
-    // pub fn random_range(&mut self, min: U256, max: U256) -> U256 {
-    //     assert!(max > min, "Max must be greater than min");
-    //     let rand = self.generate();
-    //     min + (rand % (max - min + U256::from(1)))
-    // }
+    #[test]
+    fn reset_pool_and_draw_next_exhausts_without_repeats() {
+        let vm = TestVM::default();
+        let mut contract = PureRandom::from(&vm);
+
+        contract.reset_pool(U256::from(5));
+        let mut drawn: Vec<U256> = (0..5).map(|_| contract.draw_next()).collect();
+        drawn.sort();
+        let expected: Vec<U256> = (1..=5).map(U256::from).collect();
+        assert_eq!(drawn, expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "pool exhausted")]
+    fn draw_next_reverts_when_exhausted() {
+        let vm = TestVM::default();
+        let mut contract = PureRandom::from(&vm);
+        contract.reset_pool(U256::from(1));
+        contract.draw_next();
+        contract.draw_next();
+    }
+
+    #[test]
+    fn reset_pool_starts_a_fresh_range_even_with_leftovers() {
+        let vm = TestVM::default();
+        let mut contract = PureRandom::from(&vm);
+
+        contract.reset_pool(U256::from(3));
+        contract.draw_next();
+        contract.draw_next();
+
+        // One number from the previous generation was never drawn; a fresh
+        // `reset_pool` must still hand out a clean 1..=4 range.
+        contract.reset_pool(U256::from(4));
+        let mut drawn: Vec<U256> = (0..4).map(|_| contract.draw_next()).collect();
+        drawn.sort();
+        let expected: Vec<U256> = (1..=4).map(U256::from).collect();
+        assert_eq!(drawn, expected);
+    }
+
+    #[test]
+    fn random_range_stays_within_bounds() {
+        let vm = TestVM::default();
+        let mut contract = PureRandom::from(&vm);
+        for _ in 0..10 {
+            let value = contract.random_range(U256::from(1), U256::from(6));
+            assert!(value >= U256::from(1) && value <= U256::from(6));
+        }
+    }
+
+    #[test]
+    fn draw_next_records_picked_numbers_as_drawn() {
+        let vm = TestVM::default();
+        let mut contract = PureRandom::from(&vm);
+
+        contract.reset_pool(U256::from(5));
+        let generation = contract.pool_generation.get();
+        let picked = contract.draw_next();
+
+        assert!(contract.drawn_numbers.get(generation).get(picked));
+        assert!(!contract.drawn_numbers.get(generation).get(picked + U256::from(1)));
+    }
+
+    #[test]
+    fn erc721_transfer_approve_and_operator_flows() {
+        let vm = TestVM::default();
+        let mut contract = PureRandom::from(&vm);
+        let owner = msg::sender();
+        let approved = Address::from([1u8; 20]);
+        let operator = Address::from([2u8; 20]);
+        let stranger = Address::from([3u8; 20]);
+
+        let token_id = contract.mint_card();
+        assert_eq!(contract.erc721.owner_of(token_id).unwrap(), owner);
+        assert_eq!(contract.erc721.balance_of(owner), U256::from(1));
+
+        // An approved address may transfer on the owner's behalf.
+        contract.erc721.approve(approved, token_id).unwrap();
+        vm.set_sender(approved);
+        contract.erc721.transfer_from(owner, approved, token_id).unwrap();
+        assert_eq!(contract.erc721.owner_of(token_id).unwrap(), approved);
+
+        // An operator approved for all of `approved`'s tokens may transfer too.
+        vm.set_sender(approved);
+        contract.erc721.set_approval_for_all(operator, true);
+        vm.set_sender(operator);
+        contract.erc721.transfer_from(approved, stranger, token_id).unwrap();
+        assert_eq!(contract.erc721.owner_of(token_id).unwrap(), stranger);
+    }
+
+    #[test]
+    fn erc721_transfer_rejects_unapproved_caller() {
+        let vm = TestVM::default();
+        let mut contract = PureRandom::from(&vm);
+        let owner = msg::sender();
+        let stranger = Address::from([9u8; 20]);
+
+        let token_id = contract.mint_card();
+        vm.set_sender(stranger);
+        assert!(matches!(
+            contract.erc721.transfer_from(owner, stranger, token_id),
+            Err(Erc721Error::NotApproved(_))
+        ));
+    }
+
+    #[test]
+    fn mark_succeeds_for_owner_once_number_is_drawn() {
+        let vm = TestVM::default();
+        let mut contract = PureRandom::from(&vm);
+
+        let token_id = contract.mint_card();
+        let numbers = contract.card_numbers(token_id).unwrap();
+        let number = numbers.into_iter().find(|n| !n.is_zero()).unwrap();
+
+        contract.reset_pool(U256::from(1));
+        contract.drawn_numbers.setter(contract.pool_generation.get()).setter(number).set(true);
+
+        assert!(contract.mark(token_id, number).unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "not authorized for token")]
+    fn mark_rejects_caller_without_authorization() {
+        let vm = TestVM::default();
+        let mut contract = PureRandom::from(&vm);
+        let stranger = Address::from([9u8; 20]);
+
+        let token_id = contract.mint_card();
+        let numbers = contract.card_numbers(token_id).unwrap();
+        let number = numbers.into_iter().find(|n| !n.is_zero()).unwrap();
+        contract.drawn_numbers.setter(contract.pool_generation.get()).setter(number).set(true);
+
+        vm.set_sender(stranger);
+        contract.mark(token_id, number).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "number has not been drawn")]
+    fn mark_rejects_number_that_was_never_drawn() {
+        let vm = TestVM::default();
+        let mut contract = PureRandom::from(&vm);
+
+        let token_id = contract.mint_card();
+        let numbers = contract.card_numbers(token_id).unwrap();
+        let number = numbers.into_iter().find(|n| !n.is_zero()).unwrap();
+
+        contract.mark(token_id, number).unwrap();
+    }
 }